@@ -0,0 +1,51 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    tauri_build::build();
+
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let web_dist = manifest_dir.join("..").join("..").join("web").join("dist");
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let dest_path = out_dir.join("embedded_assets.rs");
+
+    let mut entries = Vec::new();
+    if web_dist.is_dir() {
+        collect_files(&web_dist, &web_dist, &mut entries);
+    }
+    // Deterministic ordering keeps the generated file (and incremental rebuilds) stable.
+    entries.sort();
+
+    let mut generated = String::from("pub static EMBEDDED_ASSETS: &[(&str, &[u8])] = &[\n");
+    for (url_path, file_path) in &entries {
+        generated.push_str(&format!("    ({url_path:?}, include_bytes!({file_path:?})),\n"));
+    }
+    generated.push_str("];\n");
+
+    fs::write(&dest_path, generated).expect("failed to write embedded_assets.rs");
+
+    println!("cargo:rerun-if-changed={}", web_dist.display());
+}
+
+/// Walks `directory` recursively and records `(url_path, absolute_file_path)` pairs
+/// relative to `root`, e.g. `web/dist/assets/app.js` becomes `/assets/app.js`.
+fn collect_files(root: &Path, directory: &Path, entries: &mut Vec<(String, String)>) {
+    let Ok(read_dir) = fs::read_dir(directory) else {
+        return;
+    };
+
+    for entry in read_dir.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_files(root, &path, entries);
+            continue;
+        }
+
+        let Ok(relative) = path.strip_prefix(root) else {
+            continue;
+        };
+        let url_path = format!("/{}", relative.to_string_lossy().replace('\\', "/"));
+        entries.push((url_path, path.display().to_string()));
+    }
+}
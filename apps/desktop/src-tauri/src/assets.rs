@@ -0,0 +1,91 @@
+//! Embedded copy of `web/dist`, bundled into the launcher binary at build time by
+//! `build.rs`, served in-process when there's no usable on-disk install to hand off
+//! to (missing `web-dist`, or the sidecar itself couldn't be spawned).
+
+use tiny_http::{Header, Response, Server};
+
+include!(concat!(env!("OUT_DIR"), "/embedded_assets.rs"));
+
+fn lookup(url_path: &str) -> Option<&'static [u8]> {
+    EMBEDDED_ASSETS
+        .iter()
+        .find(|(path, _)| *path == url_path)
+        .map(|(_, bytes)| *bytes)
+}
+
+fn mime_type_for(url_path: &str) -> &'static str {
+    match url_path.rsplit('.').next() {
+        Some("html") => "text/html; charset=utf-8",
+        Some("js") | Some("mjs") => "text/javascript; charset=utf-8",
+        Some("css") => "text/css; charset=utf-8",
+        Some("json") => "application/json; charset=utf-8",
+        Some("svg") => "image/svg+xml",
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("ico") => "image/x-icon",
+        Some("woff2") => "font/woff2",
+        Some("woff") => "font/woff",
+        Some("wasm") => "application/wasm",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Maps a request path to an embedded asset path, falling back to `index.html` for
+/// directory-style requests (`/`, `/dashboard`) so client-side routing keeps working.
+fn resolve_asset(url_path: &str) -> (&'static str, &'static [u8]) {
+    let candidates = [
+        url_path.to_string(),
+        format!("{}/index.html", url_path.trim_end_matches('/')),
+    ];
+
+    for candidate in &candidates {
+        if let Some((canonical_path, bytes)) = EMBEDDED_ASSETS.iter().find(|(path, _)| path == candidate) {
+            return (canonical_path, bytes);
+        }
+    }
+
+    ("/index.html", lookup("/index.html").unwrap_or(&[]))
+}
+
+fn handle_request(request: tiny_http::Request) {
+    let (resolved_path, bytes) = resolve_asset(request.url());
+    let mime = mime_type_for(resolved_path);
+
+    let header = Header::from_bytes(&b"Content-Type"[..], mime.as_bytes())
+        .expect("static content-type header is always valid");
+    let response = Response::from_data(bytes).with_header(header);
+
+    let _ = request.respond(response);
+}
+
+/// Handle to a running embedded server, returned by [`start_embedded_server`] so
+/// the caller can shut it down again instead of leaking the listener and its
+/// background thread when the launcher later restarts or stops the runtime.
+pub struct EmbeddedServerHandle {
+    server: std::sync::Arc<Server>,
+}
+
+impl EmbeddedServerHandle {
+    /// Unblocks the background thread's `incoming_requests()` loop so it exits and
+    /// the bound port is released.
+    pub fn stop(&self) {
+        self.server.unblock();
+    }
+}
+
+/// Binds a tiny in-process HTTP server to `host:port` (the same host the real
+/// sidecar binds to, so LAN clients aren't left pointed at a loopback-only
+/// listener) and serves embedded assets from a background thread until `stop` is
+/// called on the returned handle.
+pub fn start_embedded_server(host: &str, port: u16) -> Result<EmbeddedServerHandle, String> {
+    let server = std::sync::Arc::new(Server::http((host, port)).map_err(|error| error.to_string())?);
+
+    let worker = server.clone();
+    std::thread::spawn(move || {
+        for request in worker.incoming_requests() {
+            handle_request(request);
+        }
+    });
+
+    Ok(EmbeddedServerHandle { server })
+}
@@ -0,0 +1,41 @@
+//! Access token for LAN-exposed instances. The launcher only provisions and
+//! persists the token; the sidecar is the one that actually enforces it, trusting
+//! `127.0.0.1` unconditionally and rejecting LAN requests that don't present it.
+
+use std::path::Path;
+
+use rand::RngCore;
+
+const AUTH_TOKEN_FILENAME: &str = "rms-local-auth-token.txt";
+
+/// Reuses the persisted access token if one exists, otherwise generates and
+/// persists a fresh one. Called on every sidecar start so a rotated token (written
+/// by [`persist_new_token`]) is picked up on the next attempt.
+pub fn load_or_create_token(app_data_dir: &Path) -> Result<String, String> {
+    let token_path = app_data_dir.join(AUTH_TOKEN_FILENAME);
+
+    if let Ok(existing) = std::fs::read_to_string(&token_path) {
+        let trimmed = existing.trim();
+        if !trimmed.is_empty() {
+            return Ok(trimmed.to_string());
+        }
+    }
+
+    persist_new_token(app_data_dir)
+}
+
+/// Generates a new random token and overwrites the persisted copy, e.g. when the
+/// user rotates credentials for a LAN-exposed instance.
+pub fn persist_new_token(app_data_dir: &Path) -> Result<String, String> {
+    let token = generate_token();
+    let token_path = app_data_dir.join(AUTH_TOKEN_FILENAME);
+    std::fs::write(&token_path, &token).map_err(|error| error.to_string())?;
+    crate::restrict_to_owner(&token_path)?;
+    Ok(token)
+}
+
+fn generate_token() -> String {
+    let mut bytes = [0u8; 24];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
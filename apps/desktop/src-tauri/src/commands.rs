@@ -0,0 +1,77 @@
+use tauri::{AppHandle, Manager, State};
+
+use crate::state::{RuntimeInfo, RuntimeState};
+use crate::auth;
+
+/// Returns a snapshot of the current runtime state (port, URLs, db path, health)
+/// so the status page can repopulate itself on reload instead of relying on
+/// `window.eval` calls that may have fired before the page was ready.
+#[tauri::command]
+pub fn get_runtime_info(state: State<'_, RuntimeState>) -> RuntimeInfo {
+    RuntimeInfo::from(&*state.lock())
+}
+
+/// Kills the running sidecar, if any, and asks the supervisor to bring it back up
+/// immediately (bypassing the crash backoff, since this is an intentional restart).
+#[tauri::command]
+pub fn restart_server(state: State<'_, RuntimeState>) {
+    let mut inner = state.lock();
+    inner.restart_requested = true;
+    if let Some(child) = inner.child.take() {
+        let _ = child.kill();
+    }
+    if let Some(embedded_server) = inner.embedded_server.take() {
+        embedded_server.stop();
+    }
+}
+
+/// Kills the running sidecar, if any, and tells the supervisor to stay down until
+/// `restart_server` is called again.
+#[tauri::command]
+pub fn stop_server(state: State<'_, RuntimeState>) {
+    let mut inner = state.lock();
+    inner.stop_requested = true;
+    if let Some(child) = inner.child.take() {
+        let _ = child.kill();
+    }
+    if let Some(embedded_server) = inner.embedded_server.take() {
+        embedded_server.stop();
+    }
+}
+
+/// Forces a fresh restart. The startup sequence already reselects a port on every
+/// attempt, so this is equivalent to `restart_server` and exists as a distinct,
+/// more discoverable command for the "port seems stuck" case.
+#[tauri::command]
+pub fn reselect_port(state: State<'_, RuntimeState>) {
+    restart_server(state)
+}
+
+/// Lets the user opt into (or out of) HTTPS for LAN sharing. Takes effect on the
+/// next restart, so callers should follow up with `restart_server` once the user
+/// confirms.
+#[tauri::command]
+pub fn set_tls_enabled(state: State<'_, RuntimeState>, enabled: bool) {
+    state.lock().tls_enabled = enabled;
+}
+
+/// Generates a new LAN access token, persists it, and asks the supervisor for a
+/// quick sidecar restart so it takes effect. This only restarts the sidecar
+/// process, not the launcher window or the open browser tab.
+#[tauri::command]
+pub fn rotate_auth_token(app: AppHandle, state: State<'_, RuntimeState>) -> Result<(), String> {
+    let app_data_dir = app.path().app_data_dir().map_err(|error| error.to_string())?;
+    let token = auth::persist_new_token(&app_data_dir)?;
+
+    let mut inner = state.lock();
+    inner.auth_token = token;
+    inner.restart_requested = true;
+    if let Some(child) = inner.child.take() {
+        let _ = child.kill();
+    }
+    if let Some(embedded_server) = inner.embedded_server.take() {
+        embedded_server.stop();
+    }
+
+    Ok(())
+}
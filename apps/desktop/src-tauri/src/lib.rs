@@ -1,23 +1,59 @@
+use std::collections::VecDeque;
 use std::fs;
+use std::io::{Read, Write};
 use std::net::{SocketAddr, TcpListener, TcpStream, UdpSocket};
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
 use tauri::Manager;
 use tauri_plugin_opener::OpenerExt;
 use tauri_plugin_shell::{process::CommandEvent, ShellExt};
 
+mod assets;
+mod auth;
+mod commands;
+mod state;
+mod tls;
+
+use state::{RuntimeState, ServerHealth};
+
 const SERVER_HOST: &str = "0.0.0.0";
 const LOCALHOST: &str = "127.0.0.1";
 const SIDECAR_BINARY: &str = "rms-server-sidecar";
 const WINDOW_LABEL: &str = "main";
-
-fn format_http_url(host: &str, port: u16) -> String {
-    if port == 80 {
-        return format!("http://{host}");
+/// Env var the sidecar reads its LAN access token from, instead of a `--auth-token`
+/// CLI flag that would be visible to any local user via `ps`/`/proc/<pid>/cmdline`.
+const AUTH_TOKEN_ENV_VAR: &str = "RMS_AUTH_TOKEN";
+
+/// Initial delay before the first restart attempt after an unexpected exit.
+const RESTART_BACKOFF_INITIAL: Duration = Duration::from_millis(500);
+/// Backoff is doubled after each failed attempt, capped at this value.
+const RESTART_BACKOFF_MAX: Duration = Duration::from_secs(30);
+/// A sidecar that stays up this long is considered healthy again, resetting the backoff.
+const HEALTHY_UPTIME_THRESHOLD: Duration = Duration::from_secs(60);
+/// Number of automatic restarts allowed after consecutive fast failures; one more
+/// fast failure after the last of them gives up instead of looping forever.
+const MAX_CONSECUTIVE_FAILURES: u32 = 6;
+
+/// Path the sidecar only answers `200` on once migrations have run and routes are
+/// mounted, used instead of a bare TCP connect for readiness.
+const HEALTH_CHECK_PATH: &str = "/healthz";
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+const HEALTH_CHECK_POLL_INTERVAL: Duration = Duration::from_millis(200);
+/// How many of the sidecar's most recent stderr lines to keep around so a
+/// readiness timeout can be reported with something more useful than "timed out".
+const MAX_CAPTURED_STDERR_LINES: usize = 20;
+
+fn format_http_url(host: &str, port: u16, use_tls: bool) -> String {
+    let scheme = if use_tls { "https" } else { "http" };
+    let default_port = if use_tls { 443 } else { 80 };
+
+    if port == default_port {
+        return format!("{scheme}://{host}");
     }
 
-    format!("http://{host}:{port}")
+    format!("{scheme}://{host}:{port}")
 }
 
 fn detect_lan_ip() -> String {
@@ -54,34 +90,86 @@ fn set_runtime_info(
     local_url: &str,
     lan_url: &str,
     db_path: &str,
+    auth_token: &str,
 ) {
     let script = format!(
-        "window.setRuntimeInfo?.({local_url:?}, {lan_url:?}, {db_path:?});"
+        "window.setRuntimeInfo?.({local_url:?}, {lan_url:?}, {db_path:?}, {auth_token:?});"
     );
     eval_status_script(app, &script);
 }
 
+/// Records the sidecar's health in the shared [`RuntimeState`] and tells the
+/// status page via `window.setServerState?.(...)` so both the managed-state
+/// commands and any still-listening `window.eval` callback agree.
+fn set_server_state(app: &tauri::AppHandle, health: ServerHealth) {
+    app.state::<RuntimeState>().lock().health = health;
+
+    let script = format!("window.setServerState?.({:?});", health.as_str());
+    eval_status_script(app, &script);
+}
+
 fn reserve_local_port() -> Result<u16, String> {
     let listener = TcpListener::bind((LOCALHOST, 0)).map_err(|error| error.to_string())?;
     let address = listener.local_addr().map_err(|error| error.to_string())?;
     Ok(address.port())
 }
 
-fn wait_for_server(port: u16, timeout: Duration) -> bool {
+/// Restricts `path` to owner-only access (`0600`); a no-op on non-Unix platforms.
+/// Used for the LAN auth token and the TLS private key so another account on a
+/// shared machine can't read the credential straight off disk.
+#[cfg(unix)]
+fn restrict_to_owner(path: &Path) -> Result<(), String> {
+    use std::os::unix::fs::PermissionsExt;
+    fs::set_permissions(path, fs::Permissions::from_mode(0o600)).map_err(|error| error.to_string())
+}
+
+#[cfg(not(unix))]
+fn restrict_to_owner(_path: &Path) -> Result<(), String> {
+    Ok(())
+}
+
+/// Polls `path` on `127.0.0.1:port` until it answers HTTP `200`, or `timeout`
+/// elapses. A bare TCP connect succeeds the instant the listener binds, before the
+/// database is migrated or routes are mounted, so this checks an actual response
+/// from the application instead.
+fn wait_for_server(port: u16, path: &str, timeout: Duration, poll_interval: Duration) -> bool {
     let deadline = Instant::now() + timeout;
     let address = SocketAddr::from(([127, 0, 0, 1], port));
 
     while Instant::now() < deadline {
-        if TcpStream::connect_timeout(&address, Duration::from_millis(250)).is_ok() {
+        if http_get_status_is_ok(&address, path) {
             return true;
         }
 
-        std::thread::sleep(Duration::from_millis(200));
+        std::thread::sleep(poll_interval);
     }
 
     false
 }
 
+/// Issues a bare-bones `GET` over a fresh connection and reports whether the
+/// status line reads `200`.
+fn http_get_status_is_ok(address: &SocketAddr, path: &str) -> bool {
+    let Ok(mut stream) = TcpStream::connect_timeout(address, Duration::from_millis(250)) else {
+        return false;
+    };
+    let _ = stream.set_read_timeout(Some(Duration::from_millis(500)));
+
+    let request = format!("GET {path} HTTP/1.1\r\nHost: 127.0.0.1\r\nConnection: close\r\n\r\n");
+    if stream.write_all(request.as_bytes()).is_err() {
+        return false;
+    }
+
+    let mut response = Vec::new();
+    let _ = stream.read_to_end(&mut response);
+
+    let Some(status_line_end) = response.iter().position(|&byte| byte == b'\n') else {
+        return false;
+    };
+    let status_line = String::from_utf8_lossy(&response[..status_line_end]);
+    status_line.split_whitespace().nth(1) == Some("200")
+}
+
 fn has_index_html(directory: &Path) -> bool {
     directory.join("index.html").is_file()
 }
@@ -148,31 +236,96 @@ fn resolve_web_dist(app: &tauri::AppHandle) -> PathBuf {
     workspace_path
 }
 
-fn start_sidecar(app: &tauri::AppHandle) -> Result<(), String> {
+/// Outcome of one sidecar attempt, used by the supervisor to decide whether to
+/// back off and retry or to give up.
+enum SidecarOutcome {
+    /// The process exited (or disconnected) after having been running. `Some(code)`
+    /// carries the exit code when the OS reported one.
+    Exited(Option<i32>),
+    /// The process never answered `/healthz` with `200` within the startup timeout.
+    /// Carries a detail message (including any captured stderr) for diagnosis.
+    ReadinessTimeout(String),
+    /// The runtime could not be started at all (no free port, no app data dir,
+    /// certificate or token provisioning failed, or the embedded fallback server
+    /// could not bind). A sidecar that fails to spawn falls back to embedded assets.
+    SpawnFailed(String),
+    /// No usable sidecar/web-dist install was found, so embedded assets are being
+    /// served in-process instead. This is a degraded but working terminal state,
+    /// not a crash, so the supervisor should not apply restart backoff to it.
+    ServedEmbeddedAssets,
+}
+
+/// Spawns one instance of the sidecar, waits for it to report readiness, opens the
+/// browser on success, and then blocks until the process terminates. Every step of
+/// the startup sequence (port, db path, web assets, args) is rebuilt from scratch so
+/// each call is a fully independent attempt.
+async fn run_sidecar_once(app: &tauri::AppHandle) -> SidecarOutcome {
     append_log(app, "Preparing local runtime...");
 
-    let server_port = reserve_local_port()?;
+    let server_port = match reserve_local_port() {
+        Ok(port) => port,
+        Err(error) => return SidecarOutcome::SpawnFailed(error),
+    };
     append_log(app, &format!("Selected available port: {server_port}"));
 
-    let app_data_dir = app.path().app_data_dir().map_err(|error| error.to_string())?;
-    fs::create_dir_all(&app_data_dir).map_err(|error| error.to_string())?;
+    let app_data_dir = match app.path().app_data_dir() {
+        Ok(dir) => dir,
+        Err(error) => return SidecarOutcome::SpawnFailed(error.to_string()),
+    };
+    if let Err(error) = fs::create_dir_all(&app_data_dir) {
+        return SidecarOutcome::SpawnFailed(error.to_string());
+    }
 
     let db_path = app_data_dir.join("rms-local.db");
     let db_path_text = db_path.display().to_string();
 
     let web_dist = resolve_web_dist(app);
     let web_dist_text = web_dist.display().to_string();
+    let web_dist_has_index = has_index_html(&web_dist);
 
     let lan_ip = detect_lan_ip();
-    let local_url = format_http_url(LOCALHOST, server_port);
-    let lan_url = format_http_url(&lan_ip, server_port);
+    let tls_enabled = app.state::<RuntimeState>().lock().tls_enabled;
+
+    let tls_cert_paths = if tls_enabled {
+        match tls::ensure_tls_cert(&app_data_dir, &lan_ip) {
+            Ok(paths) => Some(paths),
+            Err(error) => return SidecarOutcome::SpawnFailed(format!("failed to provision TLS certificate: {error}")),
+        }
+    } else {
+        None
+    };
+
+    let local_url = format_http_url(LOCALHOST, server_port, tls_enabled);
+    let lan_url = format_http_url(&lan_ip, server_port, tls_enabled);
 
-    set_runtime_info(app, &local_url, &lan_url, &db_path_text);
+    let auth_token = match auth::load_or_create_token(&app_data_dir) {
+        Ok(token) => token,
+        Err(error) => return SidecarOutcome::SpawnFailed(format!("failed to provision access token: {error}")),
+    };
+
+    {
+        let mut inner = app.state::<RuntimeState>().lock();
+        inner.port = Some(server_port);
+        inner.local_url = local_url.clone();
+        inner.lan_url = lan_url.clone();
+        inner.db_path = db_path_text.clone();
+        inner.auth_token = auth_token.clone();
+    }
+    set_runtime_info(app, &local_url, &lan_url, &db_path_text, &auth_token);
     append_log(app, &format!("Database path: {db_path_text}"));
     append_log(app, &format!("Serving web assets from: {web_dist_text}"));
     append_log(app, &format!("LAN URL: {lan_url}"));
+    append_log(app, &format!("Access token (required for LAN requests): {auth_token}"));
+
+    if !web_dist_has_index {
+        append_log(
+            app,
+            "No web-dist with index.html found on disk; serving embedded assets instead of the sidecar.",
+        );
+        return serve_embedded_fallback(app, server_port, &lan_ip).await;
+    }
 
-    let sidecar_args = vec![
+    let mut sidecar_args = vec![
         "--host".to_string(),
         SERVER_HOST.to_string(),
         "--port".to_string(),
@@ -183,20 +336,52 @@ fn start_sidecar(app: &tauri::AppHandle) -> Result<(), String> {
         web_dist_text,
     ];
 
+    if let Some(ref tls_cert_paths) = tls_cert_paths {
+        sidecar_args.push("--tls-cert".to_string());
+        sidecar_args.push(tls_cert_paths.cert_path.display().to_string());
+        sidecar_args.push("--tls-key".to_string());
+        sidecar_args.push(tls_cert_paths.key_path.display().to_string());
+    }
+
     append_log(app, "Starting sidecar runtime...");
 
-    let (mut rx, sidecar) = app
+    // Passed via env var rather than a `--auth-token` CLI flag: process arguments
+    // are readable by any local user through `ps`/`/proc/<pid>/cmdline`, which would
+    // defeat the point of gating LAN access behind this token.
+    let spawn_result = app
         .shell()
         .sidecar(SIDECAR_BINARY)
-        .map_err(|error| error.to_string())?
-        .args(sidecar_args)
-        .spawn()
-        .map_err(|error| error.to_string())?;
-
-    std::mem::forget(sidecar);
+        .map_err(|error| error.to_string())
+        .and_then(|command| {
+            command
+                .args(sidecar_args)
+                .env(AUTH_TOKEN_ENV_VAR, &auth_token)
+                .spawn()
+                .map_err(|error| error.to_string())
+        });
+
+    let (mut rx, child) = match spawn_result {
+        Ok(pair) => pair,
+        Err(error) => {
+            append_log(
+                app,
+                &format!("Failed to spawn sidecar ({error}); serving embedded assets instead."),
+            );
+            return serve_embedded_fallback(app, server_port, &lan_ip).await;
+        }
+    };
+    app.state::<RuntimeState>().lock().child = Some(child);
 
+    // Forward the sidecar's own log output and capture the moment it terminates so
+    // the supervisor loop below can react to it. The last few stderr lines are also
+    // kept around so a readiness timeout can be reported with more than "timed out".
+    let (terminated_tx, terminated_rx) = tokio::sync::oneshot::channel();
     let log_handle = app.clone();
+    let captured_stderr: Arc<Mutex<VecDeque<String>>> =
+        Arc::new(Mutex::new(VecDeque::with_capacity(MAX_CAPTURED_STDERR_LINES)));
+    let captured_stderr_for_task = captured_stderr.clone();
     tauri::async_runtime::spawn(async move {
+        let mut terminated_tx = Some(terminated_tx);
         while let Some(event) = rx.recv().await {
             match event {
                 CommandEvent::Stdout(line) => {
@@ -209,6 +394,18 @@ fn start_sidecar(app: &tauri::AppHandle) -> Result<(), String> {
                     let text = String::from_utf8_lossy(&line).trim().to_string();
                     if !text.is_empty() {
                         append_log(&log_handle, &format!("[sidecar:err] {text}"));
+                        let mut lines = captured_stderr_for_task
+                            .lock()
+                            .unwrap_or_else(|poisoned| poisoned.into_inner());
+                        if lines.len() == MAX_CAPTURED_STDERR_LINES {
+                            lines.pop_front();
+                        }
+                        lines.push_back(text);
+                    }
+                }
+                CommandEvent::Terminated(payload) => {
+                    if let Some(tx) = terminated_tx.take() {
+                        let _ = tx.send(payload.code);
                     }
                 }
                 _ => {}
@@ -218,19 +415,249 @@ fn start_sidecar(app: &tauri::AppHandle) -> Result<(), String> {
 
     append_log(app, "Waiting for HTTP server readiness...");
 
-    if wait_for_server(server_port, Duration::from_secs(10)) {
-        append_log(app, "Server is ready. Opening browser...");
+    let is_ready = if tls_enabled {
+        tls::wait_for_tls_server(
+            server_port,
+            HEALTH_CHECK_PATH,
+            HEALTH_CHECK_TIMEOUT,
+            HEALTH_CHECK_POLL_INTERVAL,
+        )
+    } else {
+        wait_for_server(
+            server_port,
+            HEALTH_CHECK_PATH,
+            HEALTH_CHECK_TIMEOUT,
+            HEALTH_CHECK_POLL_INTERVAL,
+        )
+    };
+
+    if !is_ready {
+        let stderr_tail = {
+            let lines = captured_stderr
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            if lines.is_empty() {
+                "(no stderr captured)".to_string()
+            } else {
+                lines.iter().cloned().collect::<Vec<_>>().join("\n")
+            }
+        };
+        let detail = format!("last sidecar stderr:\n{stderr_tail}");
+        append_log(app, &format!("Server did not become ready within timeout. {detail}"));
+        if let Some(child) = app.state::<RuntimeState>().lock().child.take() {
+            let _ = child.kill();
+        }
+        return SidecarOutcome::ReadinessTimeout(detail);
+    }
+
+    append_log(app, "Server is ready. Opening browser...");
+    if let Err(error) = app.opener().open_url(&local_url, None::<&str>) {
+        append_log(app, &format!("Failed to open browser: {error}"));
+    } else {
+        append_log(app, &format!("Opened: {local_url}"));
+    }
+
+    set_server_state(app, ServerHealth::Running);
 
-        app.opener()
-            .open_url(&local_url, None::<&str>)
-            .map_err(|error| error.to_string())?;
+    // From here on the sidecar is the thing being watched: the `CommandChild` lives
+    // in the shared `RuntimeState` (so commands can kill it) until it reports its
+    // own exit, rather than being forgotten as soon as it is spawned.
+    let exit_code = terminated_rx.await.unwrap_or_default();
+    app.state::<RuntimeState>().lock().child = None;
+    SidecarOutcome::Exited(exit_code)
+}
+
+/// Serves the assets bundled into the binary by `build.rs` directly on
+/// `server_port`, as a degraded fallback when there is no on-disk `web-dist` with
+/// an `index.html`, or the sidecar binary itself failed to spawn. The app still
+/// opens to something useful even on a broken or partial install.
+///
+/// `tiny_http` never speaks TLS, so this always serves (and advertises) plain HTTP
+/// regardless of whether the user has HTTPS enabled for the real sidecar — reusing
+/// the TLS-aware `local_url`/`lan_url` here would open the browser on `https://`
+/// against a server that can't complete a handshake.
+async fn serve_embedded_fallback(app: &tauri::AppHandle, server_port: u16, lan_ip: &str) -> SidecarOutcome {
+    let embedded_server = match assets::start_embedded_server(SERVER_HOST, server_port) {
+        Ok(handle) => handle,
+        Err(error) => return SidecarOutcome::SpawnFailed(format!("embedded asset server failed to start: {error}")),
+    };
+
+    let local_url = format_http_url(LOCALHOST, server_port, false);
+    let lan_url = format_http_url(lan_ip, server_port, false);
+    let (db_path_text, auth_token) = {
+        let mut inner = app.state::<RuntimeState>().lock();
+        inner.local_url = local_url.clone();
+        inner.lan_url = lan_url.clone();
+        inner.embedded_server = Some(embedded_server);
+        (inner.db_path.clone(), inner.auth_token.clone())
+    };
+    set_runtime_info(app, &local_url, &lan_url, &db_path_text, &auth_token);
+
+    append_log(app, "Serving embedded web assets in-process.");
+
+    if !wait_for_server(
+        server_port,
+        HEALTH_CHECK_PATH,
+        HEALTH_CHECK_TIMEOUT,
+        HEALTH_CHECK_POLL_INTERVAL,
+    ) {
+        append_log(app, "Embedded asset server did not become ready within timeout.");
+        if let Some(embedded_server) = app.state::<RuntimeState>().lock().embedded_server.take() {
+            embedded_server.stop();
+        }
+        return SidecarOutcome::ReadinessTimeout(
+            "(no stderr available; serving embedded assets)".to_string(),
+        );
+    }
 
+    append_log(app, "Embedded asset server is ready. Opening browser...");
+    if let Err(error) = app.opener().open_url(&local_url, None::<&str>) {
+        append_log(app, &format!("Failed to open browser: {error}"));
+    } else {
         append_log(app, &format!("Opened: {local_url}"));
-        return Ok(());
     }
 
-    append_log(app, "Server did not become ready within timeout.");
-    Err("Sidecar readiness timeout".to_string())
+    set_server_state(app, ServerHealth::Running);
+    SidecarOutcome::ServedEmbeddedAssets
+}
+
+/// Takes (resets to `false`) one of the request flags in the shared state and
+/// returns whatever it was set to.
+fn take_request_flag(app: &tauri::AppHandle, selector: impl Fn(&mut state::RuntimeStateInner) -> &mut bool) -> bool {
+    let mut inner = app.state::<RuntimeState>().lock();
+    let flag = selector(&mut inner);
+    std::mem::take(flag)
+}
+
+/// Blocks until `restart_server` is invoked, polling the shared state since there is
+/// no push notification from a `#[tauri::command]` back into this loop. A
+/// `stop_server` that arrives meanwhile marks the server `Stopped` and keeps waiting.
+async fn wait_for_restart_request(app: &tauri::AppHandle) {
+    loop {
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        let restart_requested = take_request_flag(app, |inner| &mut inner.restart_requested);
+        let stop_requested = take_request_flag(app, |inner| &mut inner.stop_requested);
+        if restart_requested {
+            return;
+        }
+        if stop_requested {
+            set_server_state(app, ServerHealth::Stopped);
+            append_log(app, "Server stopped by request.");
+        }
+    }
+}
+
+/// Runs the sidecar under supervision: whenever it exits unexpectedly (crash,
+/// panic, killed by the OS) the full startup sequence is re-run from scratch with
+/// exponential backoff, up to [`MAX_CONSECUTIVE_FAILURES`] fast failures in a row.
+/// An intentional `stop_server`/`restart_server` command does not count against
+/// that backoff.
+async fn supervise_sidecar(app: tauri::AppHandle) {
+    let mut backoff = RESTART_BACKOFF_INITIAL;
+    let mut consecutive_failures: u32 = 0;
+
+    loop {
+        set_server_state(&app, ServerHealth::Starting);
+        let attempt_started = Instant::now();
+        let outcome = run_sidecar_once(&app).await;
+
+        let failure_summary = match &outcome {
+            SidecarOutcome::Exited(Some(code)) => {
+                format!("sidecar exited with code {code}")
+            }
+            SidecarOutcome::Exited(None) => "sidecar exited or disconnected".to_string(),
+            SidecarOutcome::ReadinessTimeout(detail) => {
+                format!("sidecar never became ready; {detail}")
+            }
+            SidecarOutcome::SpawnFailed(error) => format!("failed to start sidecar: {error}"),
+            SidecarOutcome::ServedEmbeddedAssets => {
+                "serving embedded assets; waiting for a manual restart".to_string()
+            }
+        };
+        append_log(&app, &failure_summary);
+
+        let stop_requested = take_request_flag(&app, |inner| &mut inner.stop_requested);
+        let restart_requested = take_request_flag(&app, |inner| &mut inner.restart_requested);
+
+        // Checked before `stop_requested`: both flags land as `true` here if the user
+        // calls `restart_server` shortly after `stop_server`, before this loop gets
+        // back around to read them. Restart is the more recent intent, and treating
+        // it as a plain stop would park in `wait_for_restart_request` waiting on a
+        // flag that was already taken (and reset to `false`) on the line above,
+        // silently dropping the restart until the user clicks it again.
+        if restart_requested {
+            append_log(&app, "Restarting server by request...");
+            backoff = RESTART_BACKOFF_INITIAL;
+            consecutive_failures = 0;
+            continue;
+        }
+
+        // Checked before the `ServedEmbeddedAssets` branch below: `stop_server` kills
+        // the embedded listener (see `commands.rs`) regardless of what's currently
+        // serving, so the health state needs to follow suit here too, or the UI is
+        // left showing `Running` for a server that's no longer listening.
+        if stop_requested {
+            set_server_state(&app, ServerHealth::Stopped);
+            append_log(&app, "Server stopped by request.");
+            wait_for_restart_request(&app).await;
+            backoff = RESTART_BACKOFF_INITIAL;
+            consecutive_failures = 0;
+            continue;
+        }
+
+        if matches!(outcome, SidecarOutcome::ServedEmbeddedAssets) {
+            // Neither a stop nor a restart was requested: the embedded server is
+            // already up and will keep serving on its own thread; there's nothing
+            // more to supervise until the user asks for a real restart (e.g. after
+            // fixing the install).
+            wait_for_restart_request(&app).await;
+            backoff = RESTART_BACKOFF_INITIAL;
+            consecutive_failures = 0;
+            continue;
+        }
+
+        if attempt_started.elapsed() >= HEALTHY_UPTIME_THRESHOLD {
+            backoff = RESTART_BACKOFF_INITIAL;
+            consecutive_failures = 0;
+        } else {
+            consecutive_failures += 1;
+        }
+
+        if consecutive_failures > MAX_CONSECUTIVE_FAILURES {
+            set_server_state(&app, ServerHealth::Fatal);
+            append_log(
+                &app,
+                &format!(
+                    "Sidecar failed {consecutive_failures} times in a row without staying healthy; giving up on automatic restarts."
+                ),
+            );
+            wait_for_restart_request(&app).await;
+            backoff = RESTART_BACKOFF_INITIAL;
+            consecutive_failures = 0;
+            continue;
+        }
+
+        set_server_state(&app, ServerHealth::Reconnecting);
+        append_log(
+            &app,
+            &format!(
+                "Restarting sidecar in {}ms (attempt {consecutive_failures} of {MAX_CONSECUTIVE_FAILURES})...",
+                backoff.as_millis()
+            ),
+        );
+        tokio::time::sleep(backoff).await;
+        backoff = (backoff * 2).min(RESTART_BACKOFF_MAX);
+
+        // A `stop_server` during the backoff has no child to kill; honour it here
+        // instead of starting a sidecar that was asked to stay down.
+        if take_request_flag(&app, |inner| &mut inner.stop_requested) {
+            set_server_state(&app, ServerHealth::Stopped);
+            append_log(&app, "Server stopped by request.");
+            wait_for_restart_request(&app).await;
+            backoff = RESTART_BACKOFF_INITIAL;
+            consecutive_failures = 0;
+        }
+    }
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -238,6 +665,15 @@ pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_opener::init())
+        .manage(RuntimeState::new())
+        .invoke_handler(tauri::generate_handler![
+            commands::get_runtime_info,
+            commands::restart_server,
+            commands::stop_server,
+            commands::reselect_port,
+            commands::set_tls_enabled,
+            commands::rotate_auth_token,
+        ])
         .setup(|app| {
             if let Some(window) = app.get_webview_window(WINDOW_LABEL) {
                 if let Err(error) = window.show() {
@@ -246,10 +682,7 @@ pub fn run() {
             }
 
             let app_handle = app.handle().clone();
-            if let Err(error) = start_sidecar(&app_handle) {
-                append_log(&app_handle, &format!("Launcher error: {error}"));
-                eprintln!("[launcher] failed to start sidecar: {error}");
-            }
+            tauri::async_runtime::spawn(supervise_sidecar(app_handle));
 
             Ok(())
         })
@@ -0,0 +1,95 @@
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tauri_plugin_shell::process::CommandChild;
+
+/// Health of the supervised sidecar, mirrored to the webview via `setServerState`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServerHealth {
+    Starting,
+    Running,
+    Reconnecting,
+    Stopped,
+    Fatal,
+}
+
+impl Default for ServerHealth {
+    fn default() -> Self {
+        ServerHealth::Starting
+    }
+}
+
+impl ServerHealth {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ServerHealth::Starting => "starting",
+            ServerHealth::Running => "running",
+            ServerHealth::Reconnecting => "reconnecting",
+            ServerHealth::Stopped => "stopped",
+            ServerHealth::Fatal => "fatal",
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct RuntimeStateInner {
+    pub port: Option<u16>,
+    pub local_url: String,
+    pub lan_url: String,
+    pub db_path: String,
+    pub health: ServerHealth,
+    pub child: Option<CommandChild>,
+    pub stop_requested: bool,
+    pub restart_requested: bool,
+    /// Whether the next (re)start should serve over HTTPS using the persisted
+    /// self-signed certificate. Toggled by `set_tls_enabled` and applied on the
+    /// following restart, not retroactively to an already-running sidecar.
+    pub tls_enabled: bool,
+    /// Access token the sidecar requires from non-loopback LAN requests.
+    pub auth_token: String,
+    /// Handle to the in-process fallback server, set while `ServedEmbeddedAssets`
+    /// is serving instead of the sidecar. Taken and stopped on `stop_server`/
+    /// `restart_server` so the old listener and thread don't leak.
+    pub embedded_server: Option<crate::assets::EmbeddedServerHandle>,
+}
+
+/// Shared launcher state, registered with `app.manage(...)` and read/mutated by
+/// both the supervisor loop and the `#[tauri::command]`s invoked from the webview.
+pub struct RuntimeState(pub Mutex<RuntimeStateInner>);
+
+impl RuntimeState {
+    pub fn new() -> Self {
+        RuntimeState(Mutex::new(RuntimeStateInner::default()))
+    }
+
+    pub fn lock(&self) -> std::sync::MutexGuard<'_, RuntimeStateInner> {
+        self.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Snapshot returned to the frontend by `get_runtime_info`.
+#[derive(Serialize)]
+pub struct RuntimeInfo {
+    pub port: Option<u16>,
+    pub local_url: String,
+    pub lan_url: String,
+    pub db_path: String,
+    pub health: ServerHealth,
+    pub tls_enabled: bool,
+    pub auth_token: String,
+}
+
+impl From<&RuntimeStateInner> for RuntimeInfo {
+    fn from(inner: &RuntimeStateInner) -> Self {
+        RuntimeInfo {
+            port: inner.port,
+            local_url: inner.local_url.clone(),
+            lan_url: inner.lan_url.clone(),
+            db_path: inner.db_path.clone(),
+            health: inner.health,
+            tls_enabled: inner.tls_enabled,
+            auth_token: inner.auth_token.clone(),
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Self-signed TLS for LAN sharing. The sidecar terminates TLS itself; this module
+//! only provisions the certificate/key pair it needs and confirms, from the
+//! launcher side, that the server is actually ready over HTTPS.
+
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use rcgen::generate_simple_self_signed;
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{DigitallySignedStruct, SignatureScheme, Stream};
+
+const TLS_CERT_FILENAME: &str = "rms-local-cert.pem";
+const TLS_KEY_FILENAME: &str = "rms-local-key.pem";
+
+/// Paths to the persisted self-signed certificate/key pair used for LAN HTTPS.
+pub struct TlsCertPaths {
+    pub cert_path: PathBuf,
+    pub key_path: PathBuf,
+}
+
+/// Generates (on first run) or reuses a self-signed certificate covering
+/// `localhost`, `127.0.0.1`, and `lan_ip`, persisted under `app_data_dir` so the
+/// identity survives restarts instead of re-prompting a browser warning every
+/// launch. An existing pair is reused as is: it covers the LAN IP from the run that
+/// generated it and is not regenerated when `lan_ip` changes.
+pub fn ensure_tls_cert(app_data_dir: &Path, lan_ip: &str) -> Result<TlsCertPaths, String> {
+    let cert_path = app_data_dir.join(TLS_CERT_FILENAME);
+    let key_path = app_data_dir.join(TLS_KEY_FILENAME);
+
+    if cert_path.is_file() && key_path.is_file() {
+        return Ok(TlsCertPaths { cert_path, key_path });
+    }
+
+    let subject_alt_names = vec![
+        "localhost".to_string(),
+        "127.0.0.1".to_string(),
+        lan_ip.to_string(),
+    ];
+    let certified_key =
+        generate_simple_self_signed(subject_alt_names).map_err(|error| error.to_string())?;
+
+    std::fs::write(&cert_path, certified_key.cert.pem()).map_err(|error| error.to_string())?;
+    crate::restrict_to_owner(&cert_path)?;
+    std::fs::write(&key_path, certified_key.key_pair.serialize_pem())
+        .map_err(|error| error.to_string())?;
+    crate::restrict_to_owner(&key_path)?;
+
+    Ok(TlsCertPaths { cert_path, key_path })
+}
+
+/// Polls `path` over HTTPS on `127.0.0.1:port` until it answers `200`, or
+/// `timeout` elapses. A completed TLS handshake alone only proves the port is
+/// terminating TLS — not that the DB is migrated or routes are mounted, the same
+/// gap `wait_for_server` closes for plain HTTP — so this issues a real request
+/// over the established connection instead of stopping at the handshake.
+pub fn wait_for_tls_server(port: u16, path: &str, timeout: Duration, poll_interval: Duration) -> bool {
+    let deadline = Instant::now() + timeout;
+    let address = SocketAddr::from(([127, 0, 0, 1], port));
+
+    while Instant::now() < deadline {
+        if tls_get_status_is_ok(&address, path) {
+            return true;
+        }
+
+        std::thread::sleep(poll_interval);
+    }
+
+    false
+}
+
+/// Issues a bare-bones HTTPS `GET` over a fresh connection and reports whether the
+/// status line reads `200`, mirroring `http_get_status_is_ok` in `lib.rs` but with
+/// the TLS handshake handled underneath by `rustls::Stream`.
+fn tls_get_status_is_ok(address: &SocketAddr, path: &str) -> bool {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+
+    let config = rustls::ClientConfig::builder()
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(AcceptSelfSigned))
+        .with_no_client_auth();
+
+    let Ok(server_name) = ServerName::try_from("localhost").map(|name| name.to_owned()) else {
+        return false;
+    };
+    let Ok(mut connection) = rustls::ClientConnection::new(Arc::new(config), server_name) else {
+        return false;
+    };
+
+    let Ok(mut tcp_stream) = TcpStream::connect_timeout(address, Duration::from_millis(500)) else {
+        return false;
+    };
+    let _ = tcp_stream.set_read_timeout(Some(Duration::from_millis(500)));
+
+    let mut tls_stream = Stream::new(&mut connection, &mut tcp_stream);
+
+    let request = format!("GET {path} HTTP/1.1\r\nHost: 127.0.0.1\r\nConnection: close\r\n\r\n");
+    if tls_stream.write_all(request.as_bytes()).is_err() {
+        return false;
+    }
+
+    let mut response = Vec::new();
+    let _ = tls_stream.read_to_end(&mut response);
+
+    let Some(status_line_end) = response.iter().position(|&byte| byte == b'\n') else {
+        return false;
+    };
+    let status_line = String::from_utf8_lossy(&response[..status_line_end]);
+    status_line.split_whitespace().nth(1) == Some("200")
+}
+
+/// Accepts any certificate. The sidecar's certificate is self-signed and not in
+/// any trust store by design, so the readiness probe skips chain validation and
+/// only needs the handshake to complete before it sends its `GET`.
+#[derive(Debug)]
+struct AcceptSelfSigned;
+
+impl ServerCertVerifier for AcceptSelfSigned {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        _message: &[u8],
+        _cert: &CertificateDer<'_>,
+        _dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        Ok(HandshakeSignatureValid::assertion())
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        _message: &[u8],
+        _cert: &CertificateDer<'_>,
+        _dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        Ok(HandshakeSignatureValid::assertion())
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        vec![
+            SignatureScheme::RSA_PKCS1_SHA1,
+            SignatureScheme::ECDSA_SHA1_Legacy,
+            SignatureScheme::RSA_PKCS1_SHA256,
+            SignatureScheme::ECDSA_NISTP256_SHA256,
+            SignatureScheme::RSA_PKCS1_SHA384,
+            SignatureScheme::ECDSA_NISTP384_SHA384,
+            SignatureScheme::RSA_PKCS1_SHA512,
+            SignatureScheme::ECDSA_NISTP521_SHA512,
+            SignatureScheme::RSA_PSS_SHA256,
+            SignatureScheme::RSA_PSS_SHA384,
+            SignatureScheme::RSA_PSS_SHA512,
+            SignatureScheme::ED25519,
+            SignatureScheme::ED448,
+        ]
+    }
+}